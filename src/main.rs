@@ -1,19 +1,90 @@
 use anyhow::{Context, Result};
-use openapiv3::{OpenAPI, Operation, Parameter, ParameterSchemaOrContent, ReferenceOr, SchemaKind, Type};
-use reqwest::Method;
+use axum::{
+    extract::State,
+    response::{Html, Json},
+    routing::get,
+    Router,
+};
+use base64::{engine::general_purpose::STANDARD as BASE64_STANDARD, Engine as _};
+use openapiv3::{
+    APIKeyLocation, IntegerFormat, NumberFormat, OpenAPI, Operation, Parameter,
+    ParameterSchemaOrContent, ReferenceOr, Schema, SchemaData, SchemaKind, SecurityScheme,
+    StatusCode, StringFormat, Type, VariantOrUnknownOrEmpty,
+};
+use regex::Regex;
+use reqwest::{multipart, Method};
 use rmcp::{
     model::*,
     service::RequestContext,
+    transport::{stdio, streamable_http_server::{session::local::LocalSessionManager, StreamableHttpService}},
     ErrorData as McpError, RoleServer, ServerHandler, ServiceExt,
-    transport::stdio,
 };
 use serde_json::{json, Map, Value};
 use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
 use std::env;
 use std::sync::Arc;
 use tracing::{error, info};
 use tracing_subscriber::EnvFilter;
 
+/// Where and under what name a resolved security requirement should be applied to a request.
+#[derive(Clone, Debug)]
+enum AuthDescriptor {
+    /// `http`/`bearer`, or `oauth2`/`openIdConnect` treated as a pre-supplied bearer token.
+    Bearer { scheme_name: String },
+    /// `http`/`basic`, credentials supplied as a single `user:pass` value.
+    Basic { scheme_name: String },
+    /// `apiKey`, placed in the named header, query parameter, or cookie.
+    ApiKey {
+        scheme_name: String,
+        location: APIKeyLocation,
+        name: String,
+    },
+}
+
+/// How a tool's request body should be encoded, resolved once at generation time from
+/// the operation's declared body media types.
+#[derive(Clone, Debug)]
+enum BodyEncoding {
+    Json,
+    FormUrlEncoded,
+    /// `file_fields` names the `type: string, format: binary` properties that must be
+    /// sent as file parts rather than text fields.
+    Multipart { file_fields: Vec<String> },
+}
+
+/// Credentials for the security schemes declared by the spec, supplied out-of-band so
+/// secrets never flow through tool arguments.
+#[derive(Clone, Default)]
+struct SecurityConfig {
+    /// Fallback bearer token, used when `SECURITY_CONFIG` has no entry for the scheme.
+    bearer_token: Option<String>,
+    /// Fallback API key value, used when `SECURITY_CONFIG` has no entry for the scheme.
+    api_key: Option<String>,
+    /// Per-scheme-name credential, keyed by the name under `components.securitySchemes`.
+    scheme_values: HashMap<String, String>,
+}
+
+impl SecurityConfig {
+    /// Load credentials from `AUTH_BEARER_TOKEN`, `AUTH_API_KEY`, and `SECURITY_CONFIG`.
+    fn from_env() -> Self {
+        let scheme_values = env::var("SECURITY_CONFIG")
+            .ok()
+            .and_then(|raw| serde_json::from_str::<HashMap<String, String>>(&raw).ok())
+            .unwrap_or_default();
+
+        Self {
+            bearer_token: env::var("AUTH_BEARER_TOKEN").ok(),
+            api_key: env::var("AUTH_API_KEY").ok(),
+            scheme_values,
+        }
+    }
+
+    fn value_for(&self, scheme_name: &str) -> Option<&str> {
+        self.scheme_values.get(scheme_name).map(String::as_str)
+    }
+}
+
 /// Main application state containing configuration and OpenAPI spec
 #[derive(Clone)]
 struct OpenApiServer {
@@ -23,6 +94,25 @@ struct OpenApiServer {
     openapi_spec: Arc<OpenAPI>,
     http_client: reqwest::Client,
     tools: Arc<Vec<Tool>>,
+    /// Resolved auth requirement per tool name, built once at generation time.
+    auth_descriptors: Arc<HashMap<String, AuthDescriptor>>,
+    /// Resolved request body encoding per tool name, built once at generation time.
+    /// Absent entries default to JSON, matching the original behavior.
+    body_encodings: Arc<HashMap<String, BodyEncoding>>,
+    /// Reverse routing table from tool name to the operation it was generated from,
+    /// built once at generation time so `find_operation` is a direct map lookup.
+    routes: Arc<HashMap<String, RouteEntry>>,
+    security_config: SecurityConfig,
+}
+
+/// The operation a tool name resolves to: its HTTP method, the OpenAPI path template it
+/// came from, and the spec's `operationId` if it had one.
+#[derive(Clone, Debug)]
+struct RouteEntry {
+    method: Method,
+    path: String,
+    #[allow(dead_code)]
+    operation_id: Option<String>,
 }
 
 impl OpenApiServer {
@@ -31,17 +121,23 @@ impl OpenApiServer {
         info!("🔍 Fetching OpenAPI spec from: {}", doc_url);
 
         let http_client = reqwest::Client::new();
-        let spec_text = http_client
+        let response = http_client
             .get(&doc_url)
             .send()
             .await
-            .context("Failed to fetch OpenAPI spec")?
+            .context("Failed to fetch OpenAPI spec")?;
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let spec_text = response
             .text()
             .await
             .context("Failed to read spec body")?;
 
-        let openapi_spec: OpenAPI = serde_json::from_str(&spec_text)
-            .context("Failed to parse OpenAPI spec as JSON")?;
+        let spec_value = Self::parse_spec_text(content_type.as_deref(), &spec_text)?;
+        let openapi_spec = Self::openapi_from_value(spec_value)?;
 
         info!(
             "✅ Successfully loaded OpenAPI spec: {} v{}",
@@ -49,7 +145,7 @@ impl OpenApiServer {
             openapi_spec.info.version
         );
 
-        let tools = Self::generate_tools_from_spec(&openapi_spec);
+        let (tools, auth_descriptors, body_encodings, routes) = Self::generate_tools_from_spec(&openapi_spec);
         info!("🛠️  Generated {} tools from OpenAPI spec", tools.len());
 
         Ok(Self {
@@ -58,12 +154,293 @@ impl OpenApiServer {
             openapi_spec: Arc::new(openapi_spec),
             http_client,
             tools: Arc::new(tools),
+            auth_descriptors: Arc::new(auth_descriptors),
+            body_encodings: Arc::new(body_encodings),
+            routes: Arc::new(routes),
+            security_config: SecurityConfig::from_env(),
         })
     }
 
-    /// Generate MCP tools from OpenAPI operations
-    fn generate_tools_from_spec(spec: &OpenAPI) -> Vec<Tool> {
+    /// Parse the fetched spec body into a generic JSON value, choosing YAML or JSON based
+    /// on the response's `Content-Type` and, failing that, the first non-whitespace byte.
+    fn parse_spec_text(content_type: Option<&str>, text: &str) -> Result<Value> {
+        let looks_like_yaml = match content_type {
+            Some(content_type) if content_type.contains("yaml") || content_type.contains("yml") => true,
+            Some(content_type) if content_type.contains("json") => false,
+            _ => !matches!(text.trim_start().chars().next(), Some('{') | Some('[')),
+        };
+
+        if looks_like_yaml {
+            serde_yaml::from_str(text).context("Failed to parse OpenAPI spec as YAML")
+        } else {
+            serde_json::from_str(text).context("Failed to parse OpenAPI spec as JSON")
+        }
+    }
+
+    /// Build an `OpenAPI` (v3) document from a parsed spec value, converting it from
+    /// Swagger 2.0 first when `swagger: "2.0"` is present.
+    fn openapi_from_value(value: Value) -> Result<OpenAPI> {
+        let is_swagger2 = value.get("swagger").and_then(Value::as_str) == Some("2.0");
+
+        let v3_value = if is_swagger2 {
+            info!("🔁 Detected Swagger 2.0 document, converting to OpenAPI 3.0");
+            Self::convert_swagger2_to_v3(&value)
+        } else {
+            value
+        };
+
+        serde_json::from_value(v3_value).context("Failed to parse OpenAPI spec")
+    }
+
+    /// Convert a Swagger 2.0 document into an OpenAPI 3.0 document, as a JSON `Value` so it
+    /// can be fed straight into `openapiv3::OpenAPI`'s own deserializer.
+    fn convert_swagger2_to_v3(doc: &Value) -> Value {
+        let mut result = Map::new();
+        result.insert("openapi".to_string(), json!("3.0.3"));
+        result.insert(
+            "info".to_string(),
+            doc.get("info")
+                .cloned()
+                .unwrap_or_else(|| json!({"title": "Converted API", "version": "1.0.0"})),
+        );
+
+        let host = doc.get("host").and_then(Value::as_str).unwrap_or("localhost");
+        let base_path = doc.get("basePath").and_then(Value::as_str).unwrap_or("");
+        let schemes: Vec<&str> = doc
+            .get("schemes")
+            .and_then(Value::as_array)
+            .map(|schemes| schemes.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+        let schemes = if schemes.is_empty() { vec!["https"] } else { schemes };
+        let servers: Vec<Value> = schemes
+            .iter()
+            .map(|scheme| json!({"url": format!("{}://{}{}", scheme, host, base_path)}))
+            .collect();
+        result.insert("servers".to_string(), json!(servers));
+
+        let mut components = Map::new();
+        if let Some(definitions) = doc.get("definitions").and_then(Value::as_object) {
+            let mut definitions = json!(definitions);
+            Self::rewrite_swagger_refs(&mut definitions);
+            components.insert("schemas".to_string(), definitions);
+        }
+        if let Some(parameters) = doc.get("parameters").and_then(Value::as_object) {
+            let mut parameters = json!(parameters);
+            Self::rewrite_swagger_refs(&mut parameters);
+            components.insert("parameters".to_string(), parameters);
+        }
+        if let Some(security_definitions) = doc.get("securityDefinitions").and_then(Value::as_object) {
+            let security_schemes: Map<String, Value> = security_definitions
+                .iter()
+                .map(|(name, definition)| (name.clone(), Self::convert_security_definition(definition)))
+                .collect();
+            components.insert("securitySchemes".to_string(), json!(security_schemes));
+        }
+        if !components.is_empty() {
+            result.insert("components".to_string(), json!(components));
+        }
+
+        if let Some(security) = doc.get("security") {
+            result.insert("security".to_string(), security.clone());
+        }
+
+        let mut paths = Map::new();
+        if let Some(doc_paths) = doc.get("paths").and_then(Value::as_object) {
+            for (path, path_item) in doc_paths {
+                let mut path_item = Self::convert_path_item(path_item);
+                Self::rewrite_swagger_refs(&mut path_item);
+                paths.insert(path.clone(), path_item);
+            }
+        }
+        result.insert("paths".to_string(), json!(paths));
+
+        json!(result)
+    }
+
+    /// Rewrite every `$ref` string from the v2 `#/definitions/` and `#/parameters/` forms
+    /// to their v3 `#/components/schemas/` and `#/components/parameters/` equivalents,
+    /// walking the whole value recursively so refs nested in definitions, parameters,
+    /// request bodies, and responses are all caught.
+    fn rewrite_swagger_refs(value: &mut Value) {
+        const REF_PREFIXES: [(&str, &str); 2] = [
+            ("#/definitions/", "#/components/schemas/"),
+            ("#/parameters/", "#/components/parameters/"),
+        ];
+
+        match value {
+            Value::Object(map) => {
+                if let Some(Value::String(reference)) = map.get_mut("$ref") {
+                    for (v2_prefix, v3_prefix) in REF_PREFIXES {
+                        if let Some(name) = reference.strip_prefix(v2_prefix) {
+                            *reference = format!("{v3_prefix}{name}");
+                            break;
+                        }
+                    }
+                }
+                for nested in map.values_mut() {
+                    Self::rewrite_swagger_refs(nested);
+                }
+            }
+            Value::Array(items) => {
+                for item in items {
+                    Self::rewrite_swagger_refs(item);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Convert a v2 path item's operations, leaving any non-operation keys untouched.
+    fn convert_path_item(path_item: &Value) -> Value {
+        let Some(path_item) = path_item.as_object() else {
+            return path_item.clone();
+        };
+
+        const OPERATION_KEYS: [&str; 7] =
+            ["get", "post", "put", "delete", "patch", "options", "head"];
+
+        let converted: Map<String, Value> = path_item
+            .iter()
+            .map(|(key, value)| {
+                if OPERATION_KEYS.contains(&key.as_str()) {
+                    (key.clone(), Self::convert_operation(value))
+                } else {
+                    (key.clone(), value.clone())
+                }
+            })
+            .collect();
+
+        json!(converted)
+    }
+
+    /// Convert a v2 operation, turning `in: body`/`in: formData` parameters into a v3
+    /// `requestBody` and leaving the remaining parameters as-is.
+    fn convert_operation(operation: &Value) -> Value {
+        let Some(operation) = operation.as_object() else {
+            return operation.clone();
+        };
+
+        let mut remaining_params = Vec::new();
+        let mut body_schema = None;
+        let mut form_properties = Map::new();
+        let mut form_required = Vec::new();
+        let mut has_file_field = false;
+
+        for param in operation.get("parameters").and_then(Value::as_array).into_iter().flatten() {
+            match param.get("in").and_then(Value::as_str) {
+                Some("body") => {
+                    body_schema = param.get("schema").cloned();
+                }
+                Some("formData") => {
+                    let name = param.get("name").and_then(Value::as_str).unwrap_or_default().to_string();
+                    if param.get("required").and_then(Value::as_bool) == Some(true) {
+                        form_required.push(json!(name));
+                    }
+                    let is_file = param.get("type").and_then(Value::as_str) == Some("file");
+                    has_file_field = has_file_field || is_file;
+                    let mut prop = param.clone();
+                    if let Some(prop_obj) = prop.as_object_mut() {
+                        prop_obj.remove("name");
+                        prop_obj.remove("in");
+                        prop_obj.remove("required");
+                        if is_file {
+                            prop_obj.insert("type".to_string(), json!("string"));
+                            prop_obj.insert("format".to_string(), json!("binary"));
+                        }
+                    }
+                    form_properties.insert(name, prop);
+                }
+                _ => remaining_params.push(param.clone()),
+            }
+        }
+
+        let mut converted: Map<String, Value> = operation
+            .iter()
+            .filter(|(key, _)| key.as_str() != "parameters")
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+        converted.insert("parameters".to_string(), json!(remaining_params));
+
+        if let Some(schema) = body_schema {
+            converted.insert(
+                "requestBody".to_string(),
+                json!({"content": {"application/json": {"schema": schema}}}),
+            );
+        } else if !form_properties.is_empty() {
+            let content_type = if has_file_field {
+                "multipart/form-data"
+            } else {
+                "application/x-www-form-urlencoded"
+            };
+            converted.insert(
+                "requestBody".to_string(),
+                json!({
+                    "content": {
+                        content_type: {
+                            "schema": {
+                                "type": "object",
+                                "properties": form_properties,
+                                "required": form_required,
+                            }
+                        }
+                    }
+                }),
+            );
+        }
+
+        json!(converted)
+    }
+
+    /// Convert a v2 `securityDefinitions` entry into a v3 `securitySchemes` entry.
+    fn convert_security_definition(definition: &Value) -> Value {
+        match definition.get("type").and_then(Value::as_str) {
+            Some("basic") => json!({"type": "http", "scheme": "basic"}),
+            Some("apiKey") => json!({
+                "type": "apiKey",
+                "name": definition.get("name").cloned().unwrap_or_else(|| json!("")),
+                "in": definition.get("in").cloned().unwrap_or_else(|| json!("header")),
+            }),
+            Some("oauth2") => {
+                let flow_name = match definition.get("flow").and_then(Value::as_str) {
+                    Some("implicit") => "implicit",
+                    Some("password") => "password",
+                    Some("application") => "clientCredentials",
+                    Some("accessCode") => "authorizationCode",
+                    _ => "implicit",
+                };
+                let mut flow = Map::new();
+                if let Some(authorization_url) = definition.get("authorizationUrl") {
+                    flow.insert("authorizationUrl".to_string(), authorization_url.clone());
+                }
+                if let Some(token_url) = definition.get("tokenUrl") {
+                    flow.insert("tokenUrl".to_string(), token_url.clone());
+                }
+                flow.insert(
+                    "scopes".to_string(),
+                    definition.get("scopes").cloned().unwrap_or_else(|| json!({})),
+                );
+                json!({"type": "oauth2", "flows": {flow_name: flow}})
+            }
+            _ => definition.clone(),
+        }
+    }
+
+    /// Generate MCP tools from OpenAPI operations, alongside the per-tool auth descriptor
+    /// resolved from each operation's (or the spec's top-level) `security` requirement, the
+    /// per-tool request body encoding resolved from its declared body media types, and the
+    /// reverse routing table used to dispatch a tool call back to its operation.
+    fn generate_tools_from_spec(
+        spec: &OpenAPI,
+    ) -> (
+        Vec<Tool>,
+        HashMap<String, AuthDescriptor>,
+        HashMap<String, BodyEncoding>,
+        HashMap<String, RouteEntry>,
+    ) {
         let mut tools = Vec::new();
+        let mut auth_descriptors = HashMap::new();
+        let mut body_encodings = HashMap::new();
+        let mut routes = HashMap::new();
 
         for (path, path_item) in &spec.paths.paths {
             let path_item = match path_item {
@@ -72,14 +449,110 @@ impl OpenApiServer {
             };
 
             // Process each HTTP method
-            Self::process_operation_static(spec, path, &path_item.get, "GET", &mut tools);
-            Self::process_operation_static(spec, path, &path_item.post, "POST", &mut tools);
-            Self::process_operation_static(spec, path, &path_item.put, "PUT", &mut tools);
-            Self::process_operation_static(spec, path, &path_item.delete, "DELETE", &mut tools);
-            Self::process_operation_static(spec, path, &path_item.patch, "PATCH", &mut tools);
+            for (method, operation) in [
+                (Method::GET, &path_item.get),
+                (Method::POST, &path_item.post),
+                (Method::PUT, &path_item.put),
+                (Method::DELETE, &path_item.delete),
+                (Method::PATCH, &path_item.patch),
+            ] {
+                Self::process_operation_static(
+                    spec,
+                    path,
+                    operation,
+                    method,
+                    &mut tools,
+                    &mut auth_descriptors,
+                    &mut body_encodings,
+                    &mut routes,
+                );
+            }
         }
 
-        tools
+        (tools, auth_descriptors, body_encodings, routes)
+    }
+
+    /// Build a unique tool name for an operation: the spec's `operationId` when present
+    /// (sanitized to a valid identifier), otherwise `method_path`, with a numeric suffix
+    /// appended on collision with an already-registered route.
+    fn unique_tool_name(
+        routes: &HashMap<String, RouteEntry>,
+        method: &Method,
+        path: &str,
+        operation_id: Option<&str>,
+    ) -> String {
+        let base_name = operation_id
+            .map(Self::sanitize_tool_name)
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| Self::fallback_tool_name(method, path));
+
+        if !routes.contains_key(&base_name) {
+            return base_name;
+        }
+
+        (2..)
+            .map(|suffix| format!("{}_{}", base_name, suffix))
+            .find(|candidate| !routes.contains_key(candidate))
+            .expect("numeric suffixes are unbounded")
+    }
+
+    /// Sanitize an arbitrary string (e.g. an `operationId`) into a valid tool name by
+    /// replacing anything that isn't an ASCII alphanumeric or underscore.
+    fn sanitize_tool_name(raw: &str) -> String {
+        raw.chars()
+            .map(|c| if c.is_ascii_alphanumeric() || c == '_' { c } else { '_' })
+            .collect()
+    }
+
+    /// Fall back tool name for operations with no `operationId`: lowercased method plus the
+    /// path with its `/` separators turned into underscores (leading and trailing ones
+    /// trimmed) and its braces removed.
+    fn fallback_tool_name(method: &Method, path: &str) -> String {
+        format!(
+            "{}_{}",
+            method.as_str().to_lowercase(),
+            path.replace('/', "_")
+                .replace('{', "")
+                .replace('}', "")
+                .trim_matches('_')
+        )
+    }
+
+    /// Resolve the auth descriptor for an operation from its `security` requirement, falling
+    /// back to the spec's top-level requirement. The first scheme of the first requirement
+    /// object that the bridge knows how to apply wins.
+    fn resolve_auth_descriptor(spec: &OpenAPI, op: &Operation) -> Option<AuthDescriptor> {
+        let requirements = op.security.as_ref().or(spec.security.as_ref())?;
+        let schemes = &spec.components.as_ref()?.security_schemes;
+
+        requirements.iter().find_map(|requirement| {
+            requirement.keys().find_map(|scheme_name| {
+                let ReferenceOr::Item(scheme) = schemes.get(scheme_name)? else {
+                    return None;
+                };
+                Self::auth_descriptor_for_scheme(scheme_name, scheme)
+            })
+        })
+    }
+
+    fn auth_descriptor_for_scheme(scheme_name: &str, scheme: &SecurityScheme) -> Option<AuthDescriptor> {
+        match scheme {
+            SecurityScheme::HTTP { scheme, .. } if scheme.eq_ignore_ascii_case("bearer") => {
+                Some(AuthDescriptor::Bearer { scheme_name: scheme_name.to_string() })
+            }
+            SecurityScheme::HTTP { scheme, .. } if scheme.eq_ignore_ascii_case("basic") => {
+                Some(AuthDescriptor::Basic { scheme_name: scheme_name.to_string() })
+            }
+            SecurityScheme::HTTP { .. } => None,
+            SecurityScheme::APIKey { location, name, .. } => Some(AuthDescriptor::ApiKey {
+                scheme_name: scheme_name.to_string(),
+                location: location.clone(),
+                name: name.clone(),
+            }),
+            SecurityScheme::OAuth2 { .. } | SecurityScheme::OpenIDConnect { .. } => {
+                Some(AuthDescriptor::Bearer { scheme_name: scheme_name.to_string() })
+            }
+        }
     }
 
     /// Process a single operation and add it as a tool
@@ -87,17 +560,22 @@ impl OpenApiServer {
         spec: &OpenAPI,
         path: &str,
         operation: &Option<Operation>,
-        method: &str,
+        method: Method,
         tools: &mut Vec<Tool>,
+        auth_descriptors: &mut HashMap<String, AuthDescriptor>,
+        body_encodings: &mut HashMap<String, BodyEncoding>,
+        routes: &mut HashMap<String, RouteEntry>,
     ) {
         if let Some(op) = operation {
-            let tool_name = format!(
-                "{}_{}",
-                method.to_lowercase(),
-                path.replace('/', "_")
-                    .replace('{', "")
-                    .replace('}', "")
-                    .trim_matches('_')
+            let tool_name = Self::unique_tool_name(routes, &method, path, op.operation_id.as_deref());
+
+            routes.insert(
+                tool_name.clone(),
+                RouteEntry {
+                    method: method.clone(),
+                    path: path.to_string(),
+                    operation_id: op.operation_id.clone(),
+                },
             );
 
             let description = op
@@ -129,106 +607,89 @@ impl OpenApiServer {
 
                     // Extract schema from parameter
                     let schema = match &param_data.format {
-                        ParameterSchemaOrContent::Schema(schema_ref) => match schema_ref {
-                            ReferenceOr::Item(_schema) => {
-                                let mut prop = Map::new();
-                                prop.insert("type".to_string(), json!("string"));
-
-                                if let Some(desc) = &param_data.description {
-                                    prop.insert("description".to_string(), json!(desc));
-                                }
-
-                                json!(prop)
+                        ParameterSchemaOrContent::Schema(schema_ref) => {
+                            let mut prop = Self::schema_to_json(spec, schema_ref, &HashSet::new());
+                            if let (Some(obj), Some(desc)) =
+                                (prop.as_object_mut(), &param_data.description)
+                            {
+                                obj.entry("description").or_insert_with(|| json!(desc));
                             }
-                            ReferenceOr::Reference { .. } => json!({"type": "string"}),
-                        },
-                        ParameterSchemaOrContent::Content(_) => json!({"type": "string"}),
+                            prop
+                        }
+                        ParameterSchemaOrContent::Content(content) => content
+                            .values()
+                            .next()
+                            .and_then(|media| media.schema.as_ref())
+                            .map(|schema_ref| Self::schema_to_json(spec, schema_ref, &HashSet::new()))
+                            .unwrap_or_else(|| json!({"type": "string"})),
                     };
 
                     properties.insert(param_data.name.clone(), schema);
                 }
             }
 
-            // Flatten request body properties directly into parameters
+            // Flatten request body properties directly into parameters, choosing the
+            // encoding from whichever supported media type the operation declares.
             if let Some(request_body) = &op.request_body {
                 if let ReferenceOr::Item(body) = request_body {
-                    // Try to extract schema from application/json content
-                    if let Some(content) = body.content.get("application/json") {
+                    let media = body
+                        .content
+                        .get("application/json")
+                        .map(|content| (content, BodyEncoding::Json))
+                        .or_else(|| {
+                            body.content
+                                .get("application/x-www-form-urlencoded")
+                                .map(|content| (content, BodyEncoding::FormUrlEncoded))
+                        })
+                        .or_else(|| {
+                            body.content.get("multipart/form-data").map(|content| {
+                                (content, BodyEncoding::Multipart { file_fields: Vec::new() })
+                            })
+                        });
+
+                    if let Some((content, encoding)) = media {
                         if let Some(media_schema) = &content.schema {
-                            // Resolve schema (handle both inline and references)
-                            let resolved_schema = match media_schema {
-                                ReferenceOr::Item(schema) => Some(schema),
-                                ReferenceOr::Reference { reference } => {
-                                    // Extract schema name from reference like "#/components/schemas/Pet"
-                                    if let Some(schema_name) = reference.strip_prefix("#/components/schemas/") {
-                                        spec.components.as_ref()
-                                            .and_then(|c| c.schemas.get(schema_name))
-                                            .and_then(|s| match s {
-                                                ReferenceOr::Item(schema) => Some(schema),
-                                                _ => None,
-                                            })
-                                    } else {
-                                        None
+                            let body_schema = Self::schema_to_json(spec, media_schema, &HashSet::new());
+                            if let Some(body_obj) = body_schema.as_object() {
+                                let body_props = body_obj.get("properties").and_then(Value::as_object);
+
+                                if let Some(body_props) = body_props {
+                                    for (prop_name, prop_json) in body_props {
+                                        properties.insert(prop_name.clone(), prop_json.clone());
                                     }
                                 }
-                            };
-                            
-                            if let Some(schema) = resolved_schema {
-                                // Extract properties from the schema
-                                if let SchemaKind::Type(Type::Object(obj_type)) = &schema.schema_kind {
-                                    for (prop_name, prop_schema_ref) in &obj_type.properties {
-                                        // Convert the property schema to JSON
-                                        let prop_json = match prop_schema_ref {
-                                            ReferenceOr::Item(prop_schema) => {
-                                                let mut prop_obj = Map::new();
-                                                
-                                                // Determine the type
-                                                match &prop_schema.schema_kind {
-                                                    SchemaKind::Type(Type::String(_)) => {
-                                                        prop_obj.insert("type".to_string(), json!("string"));
-                                                    }
-                                                    SchemaKind::Type(Type::Number(_)) => {
-                                                        prop_obj.insert("type".to_string(), json!("number"));
-                                                    }
-                                                    SchemaKind::Type(Type::Integer(_)) => {
-                                                        prop_obj.insert("type".to_string(), json!("integer"));
-                                                    }
-                                                    SchemaKind::Type(Type::Boolean(_)) => {
-                                                        prop_obj.insert("type".to_string(), json!("boolean"));
-                                                    }
-                                                    SchemaKind::Type(Type::Array(_)) => {
-                                                        prop_obj.insert("type".to_string(), json!("array"));
-                                                    }
-                                                    SchemaKind::Type(Type::Object(_)) => {
-                                                        prop_obj.insert("type".to_string(), json!("object"));
-                                                    }
-                                                    _ => {
-                                                        prop_obj.insert("type".to_string(), json!("string"));
-                                                    }
-                                                }
-                                                
-                                                // Add description if available
-                                                if let Some(desc) = &prop_schema.schema_data.description {
-                                                    prop_obj.insert("description".to_string(), json!(desc));
-                                                }
-                                                
-                                                json!(prop_obj)
-                                            }
-                                            ReferenceOr::Reference { .. } => {
-                                                json!({"type": "string"})
+
+                                if let Some(body_required) =
+                                    body_obj.get("required").and_then(Value::as_array)
+                                {
+                                    for req_prop in body_required {
+                                        if let Some(name) = req_prop.as_str() {
+                                            if !required.iter().any(|r| r == name) {
+                                                required.push(name.to_string());
                                             }
-                                        };
-                                        
-                                        properties.insert(prop_name.clone(), prop_json);
-                                    }
-                                    
-                                    // Add required properties from the schema
-                                    for req_prop in &obj_type.required {
-                                        if !required.contains(req_prop) {
-                                            required.push(req_prop.clone());
                                         }
                                     }
                                 }
+
+                                let encoding = match encoding {
+                                    BodyEncoding::Multipart { .. } => {
+                                        let file_fields = body_props
+                                            .map(|props| {
+                                                props
+                                                    .iter()
+                                                    .filter(|(_, schema)| {
+                                                        schema.get("format").and_then(Value::as_str)
+                                                            == Some("binary")
+                                                    })
+                                                    .map(|(name, _)| name.clone())
+                                                    .collect()
+                                            })
+                                            .unwrap_or_default();
+                                        BodyEncoding::Multipart { file_fields }
+                                    }
+                                    other => other,
+                                };
+                                body_encodings.insert(tool_name.clone(), encoding);
                             }
                         }
                     }
@@ -241,6 +702,14 @@ impl OpenApiServer {
                 "required": required
             });
 
+            if let Some(descriptor) = Self::resolve_auth_descriptor(spec, op) {
+                auth_descriptors.insert(tool_name.clone(), descriptor);
+            }
+
+            let output_schema = Self::resolve_output_schema(spec, op)
+                .and_then(|schema| schema.as_object().cloned())
+                .map(Arc::new);
+
             tools.push(Tool {
                 name: Cow::Owned(tool_name.clone()),
                 description: Some(Cow::Owned(description)),
@@ -249,26 +718,292 @@ impl OpenApiServer {
                 icons: Some(Vec::new()),
                 meta: None,
                 title: None,
-                output_schema: None,
+                output_schema,
             });
         }
     }
 
-    /// Execute an API call based on tool invocation
-    async fn execute_tool(&self, tool_name: &str, arguments: Value) -> Result<String> {
-        info!("🚀 Executing tool: {} with args: {}", tool_name, arguments);
+    /// Extract the JSON-Schema for the operation's 2xx `application/json` response, if any.
+    fn resolve_output_schema(spec: &OpenAPI, op: &Operation) -> Option<Value> {
+        let success_response = op.responses.responses.iter().find_map(|(status, response)| {
+            let is_success = match status {
+                StatusCode::Code(code) => (200..300).contains(code),
+                StatusCode::Range(range) => *range == 2,
+            };
+            is_success.then_some(response)
+        })?;
+
+        let ReferenceOr::Item(response) = success_response else {
+            return None;
+        };
+        let content = response.content.get("application/json")?;
+        let schema_ref = content.schema.as_ref()?;
+
+        Some(Self::schema_to_json(spec, schema_ref, &HashSet::new()))
+    }
+
+    /// Recursively convert an OpenAPI schema into a JSON-Schema value.
+    ///
+    /// Follows `$ref`s through `components.schemas`, merging `allOf` and
+    /// translating `oneOf`/`anyOf` into their JSON-Schema equivalents.
+    /// `visited` holds the `$ref` strings already expanded on the current
+    /// path so a self-referential schema degrades to `{"type": "object"}`
+    /// instead of recursing forever.
+    fn schema_to_json(spec: &OpenAPI, schema_ref: &ReferenceOr<Schema>, visited: &HashSet<String>) -> Value {
+        match schema_ref {
+            ReferenceOr::Reference { reference } => {
+                let Some(name) = reference.strip_prefix("#/components/schemas/") else {
+                    return json!({"type": "string"});
+                };
+
+                if visited.contains(reference) {
+                    return json!({"type": "object"});
+                }
+
+                let resolved = spec
+                    .components
+                    .as_ref()
+                    .and_then(|c| c.schemas.get(name));
+
+                match resolved {
+                    Some(schema) => {
+                        let mut next_visited = visited.clone();
+                        next_visited.insert(reference.clone());
+                        Self::schema_to_json(spec, schema, &next_visited)
+                    }
+                    None => json!({"type": "string"}),
+                }
+            }
+            ReferenceOr::Item(schema) => Self::schema_item_to_json(spec, schema, visited),
+        }
+    }
+
+    /// Convert a resolved (non-`$ref`) `Schema` into a JSON-Schema value.
+    fn schema_item_to_json(spec: &OpenAPI, schema: &Schema, visited: &HashSet<String>) -> Value {
+        match &schema.schema_kind {
+            SchemaKind::AllOf { all_of } => {
+                let mut properties = Map::new();
+                let mut required = Vec::new();
+
+                for sub_ref in all_of {
+                    let sub = Self::schema_to_json(spec, sub_ref, visited);
+                    let Some(sub_obj) = sub.as_object() else { continue };
+
+                    if let Some(sub_props) = sub_obj.get("properties").and_then(Value::as_object) {
+                        for (name, value) in sub_props {
+                            properties.insert(name.clone(), value.clone());
+                        }
+                    }
+                    if let Some(sub_required) = sub_obj.get("required").and_then(Value::as_array) {
+                        for name in sub_required.iter().filter_map(Value::as_str) {
+                            if !required.iter().any(|r| r == name) {
+                                required.push(name.to_string());
+                            }
+                        }
+                    }
+                }
+
+                let mut obj = Map::new();
+                obj.insert("type".to_string(), json!("object"));
+                obj.insert("properties".to_string(), json!(properties));
+                if !required.is_empty() {
+                    obj.insert("required".to_string(), json!(required));
+                }
+                Self::apply_schema_data(&schema.schema_data, &mut obj);
+                json!(obj)
+            }
+            SchemaKind::OneOf { one_of } => {
+                let variants: Vec<Value> = one_of
+                    .iter()
+                    .map(|sub_ref| Self::schema_to_json(spec, sub_ref, visited))
+                    .collect();
+                let mut obj = Map::new();
+                obj.insert("oneOf".to_string(), json!(variants));
+                Self::apply_schema_data(&schema.schema_data, &mut obj);
+                json!(obj)
+            }
+            SchemaKind::AnyOf { any_of } => {
+                let variants: Vec<Value> = any_of
+                    .iter()
+                    .map(|sub_ref| Self::schema_to_json(spec, sub_ref, visited))
+                    .collect();
+                let mut obj = Map::new();
+                obj.insert("anyOf".to_string(), json!(variants));
+                Self::apply_schema_data(&schema.schema_data, &mut obj);
+                json!(obj)
+            }
+            SchemaKind::Type(ty) => {
+                let mut obj = Map::new();
+                match ty {
+                    Type::String(string_type) => {
+                        obj.insert("type".to_string(), json!("string"));
+                        if let Some(format) = Self::string_format_to_str(&string_type.format) {
+                            obj.insert("format".to_string(), json!(format));
+                        }
+                        if let Some(pattern) = &string_type.pattern {
+                            obj.insert("pattern".to_string(), json!(pattern));
+                        }
+                        if let Some(min_length) = string_type.min_length {
+                            obj.insert("minLength".to_string(), json!(min_length));
+                        }
+                        if let Some(max_length) = string_type.max_length {
+                            obj.insert("maxLength".to_string(), json!(max_length));
+                        }
+                        let enumeration: Vec<Value> = string_type
+                            .enumeration
+                            .iter()
+                            .flatten()
+                            .map(|v| json!(v))
+                            .collect();
+                        if !enumeration.is_empty() {
+                            obj.insert("enum".to_string(), json!(enumeration));
+                        }
+                    }
+                    Type::Number(number_type) => {
+                        obj.insert("type".to_string(), json!("number"));
+                        if let Some(format) = Self::number_format_to_str(&number_type.format) {
+                            obj.insert("format".to_string(), json!(format));
+                        }
+                        if let Some(minimum) = number_type.minimum {
+                            obj.insert("minimum".to_string(), json!(minimum));
+                        }
+                        if let Some(maximum) = number_type.maximum {
+                            obj.insert("maximum".to_string(), json!(maximum));
+                        }
+                        let enumeration: Vec<Value> = number_type
+                            .enumeration
+                            .iter()
+                            .flatten()
+                            .map(|v| json!(v))
+                            .collect();
+                        if !enumeration.is_empty() {
+                            obj.insert("enum".to_string(), json!(enumeration));
+                        }
+                    }
+                    Type::Integer(integer_type) => {
+                        obj.insert("type".to_string(), json!("integer"));
+                        if let Some(format) = Self::integer_format_to_str(&integer_type.format) {
+                            obj.insert("format".to_string(), json!(format));
+                        }
+                        if let Some(minimum) = integer_type.minimum {
+                            obj.insert("minimum".to_string(), json!(minimum));
+                        }
+                        if let Some(maximum) = integer_type.maximum {
+                            obj.insert("maximum".to_string(), json!(maximum));
+                        }
+                        let enumeration: Vec<Value> = integer_type
+                            .enumeration
+                            .iter()
+                            .flatten()
+                            .map(|v| json!(v))
+                            .collect();
+                        if !enumeration.is_empty() {
+                            obj.insert("enum".to_string(), json!(enumeration));
+                        }
+                    }
+                    Type::Boolean(_) => {
+                        obj.insert("type".to_string(), json!("boolean"));
+                    }
+                    Type::Array(array_type) => {
+                        obj.insert("type".to_string(), json!("array"));
+                        if let Some(items) = &array_type.items {
+                            let items_ref = Self::unbox_schema_ref(items);
+                            obj.insert("items".to_string(), Self::schema_to_json(spec, &items_ref, visited));
+                        }
+                        if let Some(min_items) = array_type.min_items {
+                            obj.insert("minItems".to_string(), json!(min_items));
+                        }
+                        if let Some(max_items) = array_type.max_items {
+                            obj.insert("maxItems".to_string(), json!(max_items));
+                        }
+                    }
+                    Type::Object(object_type) => {
+                        obj.insert("type".to_string(), json!("object"));
+                        let mut properties = Map::new();
+                        for (name, prop_ref) in &object_type.properties {
+                            let prop_ref = Self::unbox_schema_ref(prop_ref);
+                            properties.insert(name.clone(), Self::schema_to_json(spec, &prop_ref, visited));
+                        }
+                        obj.insert("properties".to_string(), json!(properties));
+                        if !object_type.required.is_empty() {
+                            obj.insert("required".to_string(), json!(object_type.required));
+                        }
+                    }
+                }
+                Self::apply_schema_data(&schema.schema_data, &mut obj);
+                json!(obj)
+            }
+            SchemaKind::Not { .. } | SchemaKind::Any(_) => {
+                let mut obj = Map::new();
+                obj.insert("type".to_string(), json!("object"));
+                Self::apply_schema_data(&schema.schema_data, &mut obj);
+                json!(obj)
+            }
+        }
+    }
+
+    /// `ArrayType::items` and `ObjectType::properties` box their nested schema; unwrap that
+    /// box so it can be threaded back through `schema_to_json`, which works in terms of
+    /// `ReferenceOr<Schema>`.
+    fn unbox_schema_ref(boxed_ref: &ReferenceOr<Box<Schema>>) -> ReferenceOr<Schema> {
+        match boxed_ref {
+            ReferenceOr::Item(boxed) => ReferenceOr::Item((**boxed).clone()),
+            ReferenceOr::Reference { reference } => ReferenceOr::Reference {
+                reference: reference.clone(),
+            },
+        }
+    }
+
+    /// Apply the shared `description`/`default`/`nullable` facets that every schema kind carries.
+    fn apply_schema_data(data: &SchemaData, obj: &mut Map<String, Value>) {
+        if let Some(description) = &data.description {
+            obj.insert("description".to_string(), json!(description));
+        }
+        if let Some(default) = &data.default {
+            obj.insert("default".to_string(), default.clone());
+        }
+        if data.nullable {
+            obj.insert("nullable".to_string(), json!(true));
+        }
+    }
+
+    fn string_format_to_str(format: &VariantOrUnknownOrEmpty<StringFormat>) -> Option<String> {
+        match format {
+            VariantOrUnknownOrEmpty::Item(StringFormat::Date) => Some("date".to_string()),
+            VariantOrUnknownOrEmpty::Item(StringFormat::DateTime) => Some("date-time".to_string()),
+            VariantOrUnknownOrEmpty::Item(StringFormat::Password) => Some("password".to_string()),
+            VariantOrUnknownOrEmpty::Item(StringFormat::Byte) => Some("byte".to_string()),
+            VariantOrUnknownOrEmpty::Item(StringFormat::Binary) => Some("binary".to_string()),
+            VariantOrUnknownOrEmpty::Unknown(format) => Some(format.clone()),
+            VariantOrUnknownOrEmpty::Empty => None,
+        }
+    }
+
+    fn integer_format_to_str(format: &VariantOrUnknownOrEmpty<IntegerFormat>) -> Option<String> {
+        match format {
+            VariantOrUnknownOrEmpty::Item(IntegerFormat::Int32) => Some("int32".to_string()),
+            VariantOrUnknownOrEmpty::Item(IntegerFormat::Int64) => Some("int64".to_string()),
+            VariantOrUnknownOrEmpty::Unknown(format) => Some(format.clone()),
+            VariantOrUnknownOrEmpty::Empty => None,
+        }
+    }
 
-        // Parse tool name to extract method and path
-        let parts: Vec<&str> = tool_name.splitn(2, '_').collect();
-        if parts.len() != 2 {
-            anyhow::bail!("Invalid tool name format: {}", tool_name);
+    fn number_format_to_str(format: &VariantOrUnknownOrEmpty<NumberFormat>) -> Option<String> {
+        match format {
+            VariantOrUnknownOrEmpty::Item(NumberFormat::Float) => Some("float".to_string()),
+            VariantOrUnknownOrEmpty::Item(NumberFormat::Double) => Some("double".to_string()),
+            VariantOrUnknownOrEmpty::Unknown(format) => Some(format.clone()),
+            VariantOrUnknownOrEmpty::Empty => None,
         }
+    }
 
-        let method = parts[0].to_uppercase();
-        let path_part = parts[1]; // Keep underscores for matching
+    /// Execute an API call based on tool invocation, returning both the rendered text and,
+    /// when the upstream response is a JSON object, the parsed body for `structured_content`.
+    async fn execute_tool(&self, tool_name: &str, arguments: Value) -> Result<(String, Option<Map<String, Value>>)> {
+        info!("🚀 Executing tool: {} with args: {}", tool_name, arguments);
 
-        // Find matching path in OpenAPI spec
-        let (path, operation) = self.find_operation(&method, path_part)?;
+        // Look up the route registered for this tool at generation time
+        let (method_enum, path, operation) = self.find_operation(tool_name)?;
 
         // Build the request URL
         let mut url = format!("{}{}", self.base_url.trim_end_matches('/'), path);
@@ -325,14 +1060,41 @@ impl OpenApiServer {
             }
         }
 
+        // Resolve the auth requirement (if any) for this tool and its credential value
+        let auth_descriptor = self.auth_descriptors.get(tool_name);
+        let auth_credential = auth_descriptor.and_then(|descriptor| self.resolve_credential(descriptor));
+
+        if let (Some(AuthDescriptor::ApiKey { location: APIKeyLocation::Query, name, .. }), Some(value)) =
+            (auth_descriptor, &auth_credential)
+        {
+            query_params.push((name.clone(), value.clone()));
+        }
+
         // Build and execute request
-        let method_enum = Method::from_bytes(method.as_bytes())?;
         let mut request = self.http_client.request(method_enum, &url);
 
         if !query_params.is_empty() {
             request = request.query(&query_params);
         }
 
+        if let Some(value) = &auth_credential {
+            request = match auth_descriptor {
+                Some(AuthDescriptor::Bearer { .. }) => {
+                    request.header("Authorization", format!("Bearer {}", value))
+                }
+                Some(AuthDescriptor::Basic { .. }) => {
+                    request.header("Authorization", format!("Basic {}", BASE64_STANDARD.encode(value)))
+                }
+                Some(AuthDescriptor::ApiKey { location: APIKeyLocation::Header, name, .. }) => {
+                    request.header(name.as_str(), value.clone())
+                }
+                Some(AuthDescriptor::ApiKey { location: APIKeyLocation::Cookie, name, .. }) => {
+                    request.header("Cookie", format!("{}={}", name, value))
+                }
+                Some(AuthDescriptor::ApiKey { location: APIKeyLocation::Query, .. }) | None => request,
+            };
+        }
+
         // Build body from remaining parameters (those not used in path/query/header/cookie)
         let body_params: Map<String, Value> = args_obj
             .iter()
@@ -340,9 +1102,33 @@ impl OpenApiServer {
             .map(|(k, v)| (k.clone(), v.clone()))
             .collect();
         
-        // Add body if there are any body parameters
+        // Add body if there are any body parameters, encoded per the tool's resolved
+        // body encoding (defaulting to JSON, matching the original behavior)
         if !body_params.is_empty() {
-            request = request.json(&body_params);
+            let body_encoding = self
+                .body_encodings
+                .get(tool_name)
+                .cloned()
+                .unwrap_or(BodyEncoding::Json);
+
+            request = match body_encoding {
+                BodyEncoding::Json => request.json(&body_params),
+                BodyEncoding::FormUrlEncoded => request.form(&body_params),
+                BodyEncoding::Multipart { file_fields } => {
+                    let mut form = multipart::Form::new();
+                    for (key, value) in body_params {
+                        if file_fields.contains(&key) {
+                            let blob = value.as_str().unwrap_or_default();
+                            let bytes = Self::load_multipart_file_bytes(blob)?;
+                            form = form.part(key.clone(), multipart::Part::bytes(bytes).file_name(key));
+                        } else {
+                            let text = value.as_str().map(str::to_string).unwrap_or_else(|| value.to_string());
+                            form = form.text(key, text);
+                        }
+                    }
+                    request.multipart(form)
+                }
+            };
         }
 
         info!("📡 Sending request to: {}", url);
@@ -352,6 +1138,12 @@ impl OpenApiServer {
             .context("Failed to send HTTP request")?;
 
         let status = response.status();
+        let is_json_response = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(|content_type| content_type.contains("json"))
+            .unwrap_or(false);
         let response_text = response
             .text()
             .await
@@ -359,7 +1151,11 @@ impl OpenApiServer {
 
         if status.is_success() {
             info!("✅ Request succeeded with status {}", status);
-            Ok(response_text)
+            let structured_content = is_json_response
+                .then(|| serde_json::from_str::<Value>(&response_text).ok())
+                .flatten()
+                .and_then(|value| value.as_object().cloned());
+            Ok((response_text, structured_content))
         } else {
             error!("❌ Request failed with status {}", status);
             anyhow::bail!(
@@ -370,39 +1166,193 @@ impl OpenApiServer {
         }
     }
 
-    /// Find the operation in OpenAPI spec matching method and path pattern
-    fn find_operation(&self, method: &str, path_pattern: &str) -> Result<(&str, &Operation)> {
-        for (path, path_item) in &self.openapi_spec.paths.paths {
-            // Check if path matches (accounting for path parameters)
-            let path_normalized = path
-                .replace('{', "")
-                .replace('}', "")
-                .replace('/', "_")
-                .trim_matches('_')
-                .to_string();
+    /// Look up the credential value for a resolved auth descriptor, preferring a
+    /// scheme-specific entry in `SECURITY_CONFIG` and falling back to the generic
+    /// `AUTH_BEARER_TOKEN`/`AUTH_API_KEY` env vars.
+    fn resolve_credential(&self, descriptor: &AuthDescriptor) -> Option<String> {
+        match descriptor {
+            AuthDescriptor::Bearer { scheme_name } => self
+                .security_config
+                .value_for(scheme_name)
+                .map(str::to_string)
+                .or_else(|| self.security_config.bearer_token.clone()),
+            AuthDescriptor::Basic { scheme_name } => {
+                self.security_config.value_for(scheme_name).map(str::to_string)
+            }
+            AuthDescriptor::ApiKey { scheme_name, .. } => self
+                .security_config
+                .value_for(scheme_name)
+                .map(str::to_string)
+                .or_else(|| self.security_config.api_key.clone()),
+        }
+    }
 
-            if path_pattern == path_normalized {
-                let path_item = match path_item {
-                    ReferenceOr::Item(item) => item,
-                    ReferenceOr::Reference { .. } => continue,
-                };
+    /// Resolve a multipart file argument to bytes: try it as a filesystem path first,
+    /// then fall back to treating it as a base64-encoded blob.
+    fn load_multipart_file_bytes(value: &str) -> Result<Vec<u8>> {
+        if std::path::Path::new(value).is_file() {
+            std::fs::read(value).context("Failed to read multipart file from path")
+        } else {
+            BASE64_STANDARD
+                .decode(value)
+                .context("Multipart file value was neither a readable path nor valid base64")
+        }
+    }
 
-                let operation = match method {
-                    "GET" => path_item.get.as_ref(),
-                    "POST" => path_item.post.as_ref(),
-                    "PUT" => path_item.put.as_ref(),
-                    "DELETE" => path_item.delete.as_ref(),
-                    "PATCH" => path_item.patch.as_ref(),
-                    _ => None,
-                };
+    /// Resolve a tool name to its operation via the routing table built at generation time —
+    /// a direct map lookup instead of normalizing and scanning `paths` on every invocation.
+    fn find_operation(&self, tool_name: &str) -> Result<(Method, &str, &Operation)> {
+        let route = self
+            .routes
+            .get(tool_name)
+            .with_context(|| format!("Unknown tool: {}", tool_name))?;
+
+        let path_item = self
+            .openapi_spec
+            .paths
+            .paths
+            .get(&route.path)
+            .and_then(|item| match item {
+                ReferenceOr::Item(item) => Some(item),
+                ReferenceOr::Reference { .. } => None,
+            })
+            .with_context(|| format!("Path '{}' not found for tool: {}", route.path, tool_name))?;
+
+        let operation = match route.method {
+            Method::GET => path_item.get.as_ref(),
+            Method::POST => path_item.post.as_ref(),
+            Method::PUT => path_item.put.as_ref(),
+            Method::DELETE => path_item.delete.as_ref(),
+            Method::PATCH => path_item.patch.as_ref(),
+            _ => None,
+        }
+        .with_context(|| format!("Operation not found for tool: {}", tool_name))?;
+
+        Ok((route.method.clone(), route.path.as_str(), operation))
+    }
+
+    /// Validate `arguments` against a tool's generated `input_schema`, accumulating every
+    /// violation instead of failing on the first so an LLM can correct all problems in a
+    /// single retry.
+    fn validate_arguments(input_schema: &Map<String, Value>, arguments: &Value) -> Vec<String> {
+        let mut errors = Vec::new();
+        Self::validate_value(arguments, &Value::Object(input_schema.clone()), "arguments", &mut errors);
+        errors
+    }
+
+    /// Recursively check `value` against `schema`, appending any violations found at or
+    /// below `path` to `errors`.
+    fn validate_value(value: &Value, schema: &Value, path: &str, errors: &mut Vec<String>) {
+        let Some(schema_obj) = schema.as_object() else {
+            return;
+        };
+
+        if value.is_null() && schema_obj.get("nullable").and_then(Value::as_bool) == Some(true) {
+            return;
+        }
+
+        if let Some(schema_type) = schema_obj.get("type").and_then(Value::as_str) {
+            let matches_type = match schema_type {
+                "object" => value.is_object(),
+                "array" => value.is_array(),
+                "string" => value.is_string(),
+                "boolean" => value.is_boolean(),
+                "number" => value.is_number(),
+                "integer" => value.as_i64().is_some() || value.as_u64().is_some(),
+                _ => true,
+            };
+
+            if !matches_type {
+                errors.push(format!(
+                    "{}: expected type '{}', got {}",
+                    path,
+                    schema_type,
+                    Self::json_type_name(value)
+                ));
+                return;
+            }
+        }
+
+        if let Some(enumeration) = schema_obj.get("enum").and_then(Value::as_array) {
+            if !enumeration.contains(value) {
+                errors.push(format!("{}: {} is not one of the allowed enum values", path, value));
+            }
+        }
+
+        if let (Some(pattern), Some(text)) =
+            (schema_obj.get("pattern").and_then(Value::as_str), value.as_str())
+        {
+            match Regex::new(pattern) {
+                Ok(regex) if !regex.is_match(text) => {
+                    errors.push(format!("{}: '{}' does not match pattern '{}'", path, text, pattern));
+                }
+                Ok(_) => {}
+                Err(_) => {}
+            }
+        }
 
-                if let Some(op) = operation {
-                    return Ok((path.as_str(), op));
+        if let Some(text) = value.as_str() {
+            if let Some(min_length) = schema_obj.get("minLength").and_then(Value::as_u64) {
+                if (text.chars().count() as u64) < min_length {
+                    errors.push(format!("{}: shorter than minLength {}", path, min_length));
+                }
+            }
+            if let Some(max_length) = schema_obj.get("maxLength").and_then(Value::as_u64) {
+                if (text.chars().count() as u64) > max_length {
+                    errors.push(format!("{}: longer than maxLength {}", path, max_length));
                 }
             }
         }
 
-        anyhow::bail!("Operation not found for {} {}", method, path_pattern)
+        if let Some(number) = value.as_f64() {
+            if let Some(minimum) = schema_obj.get("minimum").and_then(Value::as_f64) {
+                if number < minimum {
+                    errors.push(format!("{}: {} is less than minimum {}", path, number, minimum));
+                }
+            }
+            if let Some(maximum) = schema_obj.get("maximum").and_then(Value::as_f64) {
+                if number > maximum {
+                    errors.push(format!("{}: {} is greater than maximum {}", path, number, maximum));
+                }
+            }
+        }
+
+        if let Some(properties) = schema_obj.get("properties").and_then(Value::as_object) {
+            if let Some(required) = schema_obj.get("required").and_then(Value::as_array) {
+                for field in required.iter().filter_map(Value::as_str) {
+                    if value.get(field).is_none() {
+                        errors.push(format!("{}.{}: missing required field", path, field));
+                    }
+                }
+            }
+
+            if let Some(obj) = value.as_object() {
+                for (key, child_value) in obj {
+                    if let Some(child_schema) = properties.get(key) {
+                        Self::validate_value(child_value, child_schema, &format!("{}.{}", path, key), errors);
+                    }
+                }
+            }
+        }
+
+        if let Some(items_schema) = schema_obj.get("items") {
+            if let Some(items) = value.as_array() {
+                for (index, item) in items.iter().enumerate() {
+                    Self::validate_value(item, items_schema, &format!("{}[{}]", path, index), errors);
+                }
+            }
+        }
+    }
+
+    fn json_type_name(value: &Value) -> &'static str {
+        match value {
+            Value::Null => "null",
+            Value::Bool(_) => "boolean",
+            Value::Number(_) => "number",
+            Value::String(_) => "string",
+            Value::Array(_) => "array",
+            Value::Object(_) => "object",
+        }
     }
 }
 
@@ -464,12 +1414,30 @@ impl ServerHandler for OpenApiServer {
             None => json!({}),
         };
 
+        if let Some(tool) = self.tools.iter().find(|tool| tool.name.as_ref() == name.as_ref()) {
+            let violations = Self::validate_arguments(&tool.input_schema, &args);
+            if !violations.is_empty() {
+                let message = format!(
+                    "Argument validation failed for '{}':\n- {}",
+                    name,
+                    violations.join("\n- ")
+                );
+                error!("❌ {}", message);
+                return Ok(CallToolResult {
+                    content: vec![Content::text(message)],
+                    is_error: Some(true),
+                    meta: None,
+                    structured_content: None,
+                });
+            }
+        }
+
         match self.execute_tool(&name, args).await {
-            Ok(result) => Ok(CallToolResult {
+            Ok((result, structured_content)) => Ok(CallToolResult {
                 content: vec![Content::text(result)],
                 is_error: Some(false),
                 meta: None,
-                structured_content: None,
+                structured_content,
             }),
             Err(e) => {
                 error!("❌ Tool execution failed: {}", e);
@@ -510,13 +1478,318 @@ async fn main() -> Result<()> {
 
     info!("✨ MCP Server ready. Waiting for requests...");
 
-    // Start the server with stdio transport
-    let service = server.serve(stdio()).await.inspect_err(|e| {
-        error!("❌ Server error: {:?}", e);
-    })?;
+    let transport = env::var("TRANSPORT").unwrap_or_else(|_| "stdio".to_string());
 
-    service.waiting().await?;
+    match transport.as_str() {
+        "http" => {
+            let bind_addr = env::var("BIND_ADDR").unwrap_or_else(|_| "0.0.0.0:8080".to_string());
+            serve_http(server, bind_addr).await?;
+        }
+        _ => {
+            // Start the server with stdio transport
+            let service = server.serve(stdio()).await.inspect_err(|e| {
+                error!("❌ Server error: {:?}", e);
+            })?;
+
+            service.waiting().await?;
+        }
+    }
 
     info!("👋 Server shutting down");
     Ok(())
 }
+
+/// Serve the MCP protocol over the streamable-HTTP/SSE transport at `/mcp`, alongside two
+/// read-only debug routes: `/openapi_spec` (the loaded, possibly converted spec as JSON) and
+/// `/openapi_doc` (an HTML page listing the generated tools).
+async fn serve_http(server: OpenApiServer, bind_addr: String) -> Result<()> {
+    let mcp_server = server.clone();
+    let mcp_service = StreamableHttpService::new(
+        move || Ok(mcp_server.clone()),
+        LocalSessionManager::default().into(),
+        Default::default(),
+    );
+
+    let router = Router::new()
+        .route("/openapi_spec", get(openapi_spec_handler))
+        .route("/openapi_doc", get(openapi_doc_handler))
+        .nest_service("/mcp", mcp_service)
+        .with_state(server);
+
+    let listener = tokio::net::TcpListener::bind(bind_addr.as_str())
+        .await
+        .with_context(|| format!("Failed to bind to {}", bind_addr))?;
+
+    info!("🌐 Serving MCP over streamable HTTP at http://{}/mcp", bind_addr);
+    info!("📖 OpenAPI spec: http://{}/openapi_spec", bind_addr);
+    info!("📚 Tool docs: http://{}/openapi_doc", bind_addr);
+
+    axum::serve(listener, router)
+        .await
+        .context("HTTP server error")?;
+
+    Ok(())
+}
+
+/// `GET /openapi_spec` — the loaded (possibly Swagger-2.0-converted) spec as JSON.
+async fn openapi_spec_handler(State(server): State<OpenApiServer>) -> Json<OpenAPI> {
+    Json((*server.openapi_spec).clone())
+}
+
+/// `GET /openapi_doc` — a minimal HTML page listing the generated tools with their names,
+/// descriptions, and input schemas. Useful for debugging what the bridge derived from the
+/// upstream spec.
+async fn openapi_doc_handler(State(server): State<OpenApiServer>) -> Html<String> {
+    let mut html = format!(
+        "<html><head><title>{title} — MCP Tools</title></head><body><h1>{title}</h1><p>{count} tools generated from this spec.</p><ul>",
+        title = html_escape(&server.openapi_spec.info.title),
+        count = server.tools.len(),
+    );
+
+    for tool in server.tools.iter() {
+        html.push_str("<li><h3>");
+        html.push_str(&html_escape(&tool.name));
+        html.push_str("</h3>");
+        if let Some(description) = &tool.description {
+            html.push_str("<p>");
+            html.push_str(&html_escape(description));
+            html.push_str("</p>");
+        }
+        let schema = serde_json::to_string_pretty(&tool.input_schema).unwrap_or_default();
+        html.push_str("<pre>");
+        html.push_str(&html_escape(&schema));
+        html.push_str("</pre></li>");
+    }
+
+    html.push_str("</ul></body></html>");
+    Html(html)
+}
+
+fn html_escape(input: &str) -> String {
+    input
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec_with_schemas(schemas: Value) -> OpenAPI {
+        serde_json::from_value(json!({
+            "openapi": "3.0.3",
+            "info": {"title": "Test", "version": "1.0.0"},
+            "paths": {},
+            "components": {"schemas": schemas},
+        }))
+        .expect("test spec should deserialize")
+    }
+
+    fn schema_ref(name: &str) -> ReferenceOr<Schema> {
+        ReferenceOr::Reference {
+            reference: format!("#/components/schemas/{name}"),
+        }
+    }
+
+    #[test]
+    fn schema_to_json_resolves_named_ref() {
+        let spec = spec_with_schemas(json!({
+            "Widget": {"type": "string", "minLength": 2},
+        }));
+
+        let resolved = OpenApiServer::schema_to_json(&spec, &schema_ref("Widget"), &HashSet::new());
+
+        assert_eq!(resolved, json!({"type": "string", "minLength": 2}));
+    }
+
+    #[test]
+    fn schema_to_json_breaks_cycles() {
+        let spec = spec_with_schemas(json!({
+            "Node": {
+                "type": "object",
+                "properties": {"child": {"$ref": "#/components/schemas/Node"}},
+            },
+        }));
+
+        let resolved = OpenApiServer::schema_to_json(&spec, &schema_ref("Node"), &HashSet::new());
+
+        assert_eq!(
+            resolved.get("properties").and_then(|p| p.get("child")),
+            Some(&json!({"type": "object"}))
+        );
+    }
+
+    #[test]
+    fn schema_to_json_merges_all_of_properties_and_required() {
+        let spec = spec_with_schemas(json!({
+            "Base": {
+                "type": "object",
+                "properties": {"id": {"type": "string"}},
+                "required": ["id"],
+            },
+            "Extra": {
+                "type": "object",
+                "properties": {"name": {"type": "string"}},
+                "required": ["name"],
+            },
+            "Combined": {"allOf": [
+                {"$ref": "#/components/schemas/Base"},
+                {"$ref": "#/components/schemas/Extra"},
+            ]},
+        }));
+
+        let resolved = OpenApiServer::schema_to_json(&spec, &schema_ref("Combined"), &HashSet::new());
+
+        assert_eq!(resolved["type"], json!("object"));
+        assert_eq!(resolved["properties"]["id"], json!({"type": "string"}));
+        assert_eq!(resolved["properties"]["name"], json!({"type": "string"}));
+        let required = resolved["required"].as_array().unwrap();
+        assert!(required.contains(&json!("id")));
+        assert!(required.contains(&json!("name")));
+    }
+
+    #[test]
+    fn schema_to_json_resolves_array_item_refs() {
+        let spec = spec_with_schemas(json!({
+            "Widget": {"type": "string"},
+            "WidgetList": {"type": "array", "items": {"$ref": "#/components/schemas/Widget"}},
+        }));
+
+        let resolved = OpenApiServer::schema_to_json(&spec, &schema_ref("WidgetList"), &HashSet::new());
+
+        assert_eq!(resolved, json!({"type": "array", "items": {"type": "string"}}));
+    }
+
+    #[test]
+    fn validate_arguments_aggregates_all_violations() {
+        let input_schema = json!({
+            "type": "object",
+            "properties": {
+                "status": {"type": "string", "enum": ["open", "closed"]},
+                "count": {"type": "integer", "minimum": 1, "maximum": 10},
+            },
+            "required": ["status", "count", "owner"],
+        })
+        .as_object()
+        .unwrap()
+        .clone();
+
+        let errors = OpenApiServer::validate_arguments(
+            &input_schema,
+            &json!({"status": "pending", "count": 99}),
+        );
+
+        assert_eq!(errors.len(), 3);
+        assert!(errors.iter().any(|e| e.contains("owner") && e.contains("missing")));
+        assert!(errors.iter().any(|e| e.contains("not one of the allowed enum values")));
+        assert!(errors.iter().any(|e| e.contains("greater than maximum")));
+    }
+
+    #[test]
+    fn validate_arguments_accepts_null_for_nullable_field() {
+        let input_schema = json!({
+            "type": "object",
+            "properties": {
+                "nickname": {"type": "string", "nullable": true},
+            },
+        })
+        .as_object()
+        .unwrap()
+        .clone();
+
+        let errors = OpenApiServer::validate_arguments(&input_schema, &json!({"nickname": null}));
+
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn unique_tool_name_appends_numeric_suffix_on_collision() {
+        let mut routes = HashMap::new();
+        routes.insert(
+            "get_widgets".to_string(),
+            RouteEntry {
+                method: Method::GET,
+                path: "/widgets".to_string(),
+                operation_id: None,
+            },
+        );
+
+        let name = OpenApiServer::unique_tool_name(&routes, &Method::GET, "/widgets/legacy", Some("get widgets"));
+
+        assert_eq!(name, "get_widgets_2");
+    }
+
+    #[test]
+    fn unique_tool_name_uses_sanitized_operation_id_when_free() {
+        let routes = HashMap::new();
+
+        let name = OpenApiServer::unique_tool_name(&routes, &Method::GET, "/widgets", Some("list-widgets"));
+
+        assert_eq!(name, "list_widgets");
+    }
+
+    #[test]
+    fn convert_swagger2_to_v3_rewrites_definition_and_parameter_refs() {
+        let v2_doc = json!({
+            "swagger": "2.0",
+            "info": {"title": "Legacy", "version": "1.0.0"},
+            "host": "example.com",
+            "basePath": "/api",
+            "definitions": {
+                "Widget": {"type": "object", "properties": {"id": {"type": "string"}}},
+            },
+            "parameters": {
+                "WidgetId": {"name": "id", "in": "query", "type": "string", "required": true},
+            },
+            "paths": {
+                "/widgets": {
+                    "get": {
+                        "parameters": [{"$ref": "#/parameters/WidgetId"}],
+                        "responses": {
+                            "200": {
+                                "description": "ok",
+                                "schema": {"$ref": "#/definitions/Widget"},
+                            }
+                        },
+                    },
+                },
+            },
+        });
+
+        let v3_doc = OpenApiServer::convert_swagger2_to_v3(&v2_doc);
+
+        assert_eq!(
+            v3_doc["paths"]["/widgets"]["get"]["responses"]["200"]["schema"]["$ref"],
+            json!("#/components/schemas/Widget")
+        );
+        assert_eq!(
+            v3_doc["paths"]["/widgets"]["get"]["parameters"][0]["$ref"],
+            json!("#/components/parameters/WidgetId")
+        );
+        assert_eq!(
+            v3_doc["components"]["parameters"]["WidgetId"]["name"],
+            json!("id")
+        );
+    }
+
+    #[test]
+    fn convert_operation_routes_file_form_data_to_multipart() {
+        let v2_operation = json!({
+            "parameters": [
+                {"name": "file", "in": "formData", "type": "file", "required": true},
+                {"name": "caption", "in": "formData", "type": "string"},
+            ],
+        });
+
+        let converted = OpenApiServer::convert_operation(&v2_operation);
+        let content = &converted["requestBody"]["content"];
+
+        assert!(content.get("multipart/form-data").is_some());
+        assert_eq!(
+            content["multipart/form-data"]["schema"]["properties"]["file"],
+            json!({"type": "string", "format": "binary"})
+        );
+    }
+}